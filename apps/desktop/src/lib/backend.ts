@@ -1,10 +1,41 @@
 import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
 
+/** Which step of backend command resolution matched. */
+export type ResolutionTier = "override" | "install" | "default-venv" | "path-fallback";
+
 export interface BackendStatus {
   running: boolean;
   port: number | null;
   managed: boolean;
+  /** `cpu`, `auto`, or the pinned `GPU-<uuid>`. */
+  device: string;
+  boundFamily: "ipv4" | "ipv6" | null;
+  /** Stopped from the UI but kept loaded (keep-alive); the process is still running. */
+  standby: boolean;
+  profile: string | null;
+  /** `cuda:<index>` or `cpu` as last found by `backendDevice()`; null until it has run. */
+  activeDevice: string | null;
+  /** Port the caller asked for when the backend was already running elsewhere; use `port` to connect. */
+  requestedPort: number | null;
+  /** Workers asked of `startBackend` while the backend was already running; not applied or saved. */
+  requestedWorkers: number | null;
+  /** Same as `requestedWorkers` for the model. */
+  requestedModel: string | null;
+  /** A detached backend from an earlier session is running; `attachBackend` adopts it. */
+  reattachable: boolean;
+  /** Null when stopped or adopted from an earlier session. */
+  resolutionTier: ResolutionTier | null;
+  /** Unix seconds since which health probes have been failing; null while healthy. */
+  unhealthySince: number | null;
+  /** Readiness probe that first succeeded (`tcp` or `http:<path>`); null until ready. */
+  readyProbe: string | null;
+}
+
+export interface BackendEndpoints {
+  wsUrl: string | null;
+  baseUrl: string | null;
+  boundFamily: "ipv4" | "ipv6" | null;
 }
 
 export interface BackendPreflight {
@@ -14,12 +45,100 @@ export interface BackendPreflight {
   portValid: boolean;
   issueCode: string | null;
   message: string;
+  resolvedCommandLine: string | null;
+  resolutionTier: ResolutionTier;
+}
+
+export interface BackendLogLine {
+  stream: "stdout" | "stderr";
+  line: string;
+}
+
+export interface BackendErrorLine {
+  stream: "stdout" | "stderr";
+  line: string;
+  level: "error" | "warning";
+  afterReady: boolean;
+}
+
+export interface DesktopSettings {
+  backendCommand: string | null;
+  serverArgs: string[];
+  forceCpu: boolean;
+  gpuUuid: string | null;
+  bindFamily: "auto" | "ipv4" | "ipv6";
+  logLevel: "debug" | "info" | "warning" | "error" | "critical" | null;
+  configPath: string | null;
+  /** Passed as `--workers`; at most `SystemInfo.cpuCount`. Null keeps the backend's default. */
+  workers: number | null;
+  /** Passed as `--model`; large models get a longer readiness timeout. Null keeps the config's model. */
+  model: string | null;
+  extraArgs: string[];
+  keepAlive: boolean;
+  startTimeoutSecs: number;
+  /** Creating the process taking longer fails the start with `spawn_timeout`. */
+  spawnTimeoutSecs: number;
+  healthIntervalSecs: number;
+  healthFailureThreshold: number;
+  /** Tried in order until one succeeds: `tcp` or `http:<path>`, e.g. `http:/health`. */
+  readinessProbes: string[];
+  lastStartupBenchmarkMs: number | null;
+  lastRunBackendVersion: string | null;
+  profiles: Record<string, BackendProfile>;
+  autostartBackend: boolean;
+  /** Must name an entry of `profiles`; null starts on the default port. */
+  autostartProfile: string | null;
+  /** Only attach to and probe an externally managed backend; lifecycle commands are refused. */
+  observerMode: boolean;
+  /** Channel of the last install; `latestKeyvoxVersion` follows it. */
+  installChannel: InstallChannel;
+  /** Where backend output is kept from the next start; it always streams to the UI. */
+  logDestination: LogDestination;
+  heartbeatEnabled: boolean;
+}
+
+export interface BackendProfile {
+  command: string | null;
+  port: number | null;
+  extraArgs: string[];
+  env: Record<string, string>;
+  stack: "gpu" | "cpu" | null;
+  workers: number | null;
+  model: string | null;
 }
 
 export async function backendStatus(): Promise<BackendStatus> {
   return invoke<BackendStatus>("backend_status");
 }
 
+export interface CommandErrorPayload {
+  issueCode: string;
+  message: string;
+}
+
+/** Validation failure from a command; `issueCode` is stable, `message` is user-facing. */
+export class CommandError extends Error {
+  readonly issueCode: string;
+
+  constructor(payload: CommandErrorPayload) {
+    super(payload.message);
+    this.issueCode = payload.issueCode;
+  }
+
+  toString(): string {
+    return this.message;
+  }
+}
+
+/** Trims and validates a user-typed port; rejects with a `CommandError`. */
+export async function normalizePort(input: string): Promise<number> {
+  try {
+    return await invoke<number>("normalize_port", { input });
+  } catch (err) {
+    throw new CommandError(err as CommandErrorPayload);
+  }
+}
+
 export async function backendPreflight(preferredPort: number, command?: string): Promise<BackendPreflight> {
   return invoke<BackendPreflight>("backend_preflight", {
     preferredPort,
@@ -27,21 +146,377 @@ export async function backendPreflight(preferredPort: number, command?: string):
   });
 }
 
-export async function startBackend(preferredPort: number, command?: string): Promise<BackendStatus> {
-  return invoke<BackendStatus>("start_backend", {
-    preferredPort,
-    command,
+export interface RuntimeReport {
+  ok: boolean;
+  missing: string[];
+  /** Includes the redistributable download link when `ok` is false. */
+  message: string;
+}
+
+export async function checkWindowsRuntime(): Promise<RuntimeReport> {
+  return invoke<RuntimeReport>("check_windows_runtime");
+}
+
+export async function backendEndpoints(): Promise<BackendEndpoints> {
+  return invoke<BackendEndpoints>("backend_endpoints");
+}
+
+export interface LanReport {
+  lanAddress: string | null;
+  /** URL other devices on the network would use. */
+  url: string | null;
+  reachable: boolean;
+  issue: "not_running" | "no_lan_address" | "not_listening_on_lan" | null;
+  message: string;
+}
+
+export async function checkLanReachability(): Promise<LanReport> {
+  return invoke<LanReport>("check_lan_reachability");
+}
+
+export async function openBackendUi(): Promise<void> {
+  await invoke("open_backend_ui");
+}
+
+export interface StartBackendOptions {
+  command?: string;
+  deviceUuid?: string;
+  restartOnPortMismatch?: boolean;
+  detached?: boolean;
+  allowCpuFallback?: boolean;
+  /** Run `keyvox --migrate` first when the installed backend changed since the last run. */
+  migrateOnUpgrade?: boolean;
+  workers?: number;
+  model?: string;
+}
+
+export async function startBackend(
+  preferredPort: number,
+  options: StartBackendOptions = {},
+): Promise<BackendStatus> {
+  try {
+    return await invoke<BackendStatus>("start_backend", { preferredPort, options });
+  } catch (err) {
+    // `issueCode` is "spawn_timeout" when the process was never created, "migration_failed"
+    // when the upgrade migration failed, "workers_out_of_range", "invalid_model",
+    // "mode_disabled" in observer mode, or a preflight code.
+    throw toInstallError(err);
+  }
+}
+
+export interface BackendConfigDump {
+  command: string;
+  args: string[];
+  /** Variables set on top of the inherited environment; sensitive values read "<redacted>". */
+  env: Record<string, string>;
+  port: number;
+  host: string;
+  workingDir: string | null;
+  stack: "gpu" | "cpu";
+  profile: string | null;
+}
+
+/** How the managed backend was launched, for bug reports. Rejects if nothing was launched. */
+export async function exportBackendConfig(): Promise<BackendConfigDump> {
+  return invoke<BackendConfigDump>("export_backend_config");
+}
+
+export interface RebindResult {
+  status: BackendStatus;
+  /** The model was reloaded because the backend had to be restarted on the new port. */
+  restarted: boolean;
+}
+
+export async function rebindPort(newPort: number): Promise<RebindResult> {
+  return invoke<RebindResult>("rebind_port", { newPort });
+}
+
+export async function attachBackend(): Promise<BackendStatus> {
+  return invoke<BackendStatus>("attach_backend");
+}
+
+/** Starts and stops the backend, resolving to the milliseconds it took to accept connections. */
+export async function benchmarkStartup(port: number): Promise<number> {
+  return invoke<number>("benchmark_startup", { port });
+}
+
+export interface IpcPong {
+  payload: string;
+  receivedAtMs: number;
+}
+
+export async function pingIpc(payload: string): Promise<IpcPong> {
+  return invoke<IpcPong>("ping_ipc", { payload });
+}
+
+export interface CommandLatency {
+  statusMs: number;
+  backendConnectMs: number | null;
+  backendRoundtripMs: number | null;
+}
+
+export interface LatencyReport extends CommandLatency {
+  /** Round trip of an empty command through the webview bridge. */
+  ipcMs: number;
+}
+
+/** Splits perceived slowness into webview IPC, app-side work and backend response time. */
+export async function measureLatency(): Promise<LatencyReport> {
+  const started = performance.now();
+  await pingIpc("latency");
+  const ipcMs = performance.now() - started;
+  const latency = await invoke<CommandLatency>("measure_command_latency");
+  return { ipcMs, ...latency };
+}
+
+/** Downloads and loads the model without serving; resolves when the prewarm process exits. */
+export async function prewarmBackend(onProgress: (line: string) => void, command?: string): Promise<void> {
+  const unlisten = await listen<string>("backend-prewarm-progress", (e) => onProgress(e.payload));
+  try {
+    await invoke("prewarm_backend", { command });
+  } finally {
+    unlisten();
+  }
+}
+
+export interface MigrationReport {
+  fromVersion: number;
+  toVersion: number;
+  /** 0 when the data was already current. */
+  applied: number;
+}
+
+/** Runs `keyvox --migrate` with the backend stopped; output streams to `onProgress`. */
+export async function runBackendMigrations(
+  onProgress: (line: string) => void,
+  command?: string,
+): Promise<MigrationReport> {
+  const unlisten = await listen<string>("backend-migration-progress", (e) => onProgress(e.payload));
+  try {
+    return await invoke<MigrationReport>("run_backend_migrations", { command });
+  } finally {
+    unlisten();
+  }
+}
+
+export interface OneShotResult {
+  /** `null` when the backend was killed by a signal. */
+  exitCode: number | null;
+  stdout: string;
+  stderr: string;
+}
+
+/**
+ * Runs the backend in one one-shot mode (`--prewarm`, `--migrate` or `--help`) without the
+ * server. Rejects on disallowed args or a timeout; a failing exit code still resolves.
+ */
+export async function runBackendOneshot(
+  args: string[],
+  timeoutSecs: number,
+): Promise<OneShotResult> {
+  return invoke<OneShotResult>("run_backend_oneshot", { args, timeoutSecs });
+}
+
+export interface StopProgress {
+  elapsedMs: number;
+  timeoutMs: number;
+}
+
+/**
+ * With `timeoutSecs`, a killed backend that does not exit in time rejects with `issueCode`
+ * "stop_timeout" and stays tracked so the stop can be retried.
+ */
+/**
+ * Emergency escape from a stop stuck on an unkillable process: stops tracking the backend
+ * without waiting for it, leaving the app stopped. The process may keep running.
+ */
+export async function forceDetachBackend(): Promise<BackendStatus> {
+  return invoke<BackendStatus>("force_detach_backend");
+}
+
+export async function stopBackend(
+  timeoutSecs?: number,
+  onProgress?: (progress: StopProgress) => void,
+): Promise<BackendStatus> {
+  const unlisten = onProgress
+    ? await listen<StopProgress>("backend-stop-progress", (e) => onProgress(e.payload))
+    : null;
+  try {
+    return await invoke<BackendStatus>("stop_backend", { timeoutSecs });
+  } catch (err) {
+    // `issueCode` is "stop_timeout", or "mode_disabled" in observer mode.
+    throw toInstallError(err);
+  } finally {
+    unlisten?.();
+  }
+}
+
+export async function listProfiles(): Promise<Record<string, BackendProfile>> {
+  return invoke<Record<string, BackendProfile>>("list_profiles");
+}
+
+export async function saveProfile(name: string, profile: BackendProfile): Promise<void> {
+  await invoke("save_profile", { name, profile });
+}
+
+export async function deleteProfile(name: string): Promise<void> {
+  await invoke("delete_profile", { name });
+}
+
+export async function startBackendProfile(name: string): Promise<BackendStatus> {
+  try {
+    return await invoke<BackendStatus>("start_backend_profile", { name });
+  } catch (err) {
+    // Same `issueCode` values as `startBackend`, e.g. "spawn_timeout".
+    throw toInstallError(err);
+  }
+}
+
+export async function setBackendCommand(command: string | null): Promise<DesktopSettings> {
+  return invoke<DesktopSettings>("set_backend_command", { command });
+}
+
+export async function clearBackendCommand(): Promise<DesktopSettings> {
+  return invoke<DesktopSettings>("clear_backend_command");
+}
+
+export interface Heartbeat {
+  /** 0 announces that heartbeats were turned off. */
+  seq: number;
+  timestampMs: number;
+}
+
+/** Starts or stops `heartbeat` events; the choice is kept for the next launch. */
+export async function setHeartbeat(enabled: boolean): Promise<DesktopSettings> {
+  return invoke<DesktopSettings>("set_heartbeat", { enabled });
+}
+
+/**
+ * Calls `onStall` once heartbeats stop arriving for `stallMs` after at least one was seen, and
+ * `onRecover` when they resume. Nothing is reported while heartbeats are off.
+ */
+export async function watchHeartbeat(
+  onStall: () => void,
+  onRecover: () => void,
+  stallMs = 8000,
+): Promise<() => void> {
+  let lastSeen: number | null = null;
+  let stalled = false;
+  const unlisten = await listen<Heartbeat>("heartbeat", (e) => {
+    lastSeen = e.payload.seq === 0 ? null : Date.now();
+    if (stalled) {
+      stalled = false;
+      onRecover();
+    }
   });
+  const timer = setInterval(() => {
+    if (lastSeen !== null && !stalled && Date.now() - lastSeen > stallMs) {
+      stalled = true;
+      onStall();
+    }
+  }, 1000);
+  return () => {
+    clearInterval(timer);
+    unlisten();
+  };
+}
+
+export type LogDestination = "file" | "memory" | "off";
+
+export async function setLogDestination(dest: LogDestination): Promise<DesktopSettings> {
+  return invoke<DesktopSettings>("set_log_destination", { dest });
 }
 
-export async function stopBackend(): Promise<BackendStatus> {
-  return invoke<BackendStatus>("stop_backend");
+export async function setKeepAlive(enabled: boolean): Promise<BackendStatus> {
+  return invoke<BackendStatus>("set_keep_alive", { enabled });
+}
+
+/**
+ * Sets the minimum spacing of `event` emissions in milliseconds; 0 delivers every one.
+ * Rejects for `backend-log`, which is never coalesced.
+ */
+export async function setEventRate(event: string, intervalMs: number): Promise<void> {
+  await invoke("set_event_rate", { event, intervalMs });
+}
+
+export interface EventInfo {
+  name: string;
+  description: string;
+  /** TypeScript-style sketch of the payload. */
+  payload: string;
+}
+
+/** Every event the app emits, from the same registry the Rust side emits with. */
+export async function listEvents(): Promise<EventInfo[]> {
+  return invoke<EventInfo[]>("list_events");
+}
+
+export async function getSettings(): Promise<DesktopSettings> {
+  return invoke<DesktopSettings>("get_settings");
+}
+
+export async function updateSettings(settings: DesktopSettings): Promise<DesktopSettings> {
+  return invoke<DesktopSettings>("update_settings", { settings });
+}
+
+export async function exportSettings(): Promise<string> {
+  return invoke<string>("export_settings");
+}
+
+export async function importSettings(path: string): Promise<DesktopSettings> {
+  return invoke<DesktopSettings>("import_settings", { path });
+}
+
+export async function resetAppData(keepInstall: boolean): Promise<void> {
+  await invoke("reset_app_data", { keepInstall });
+}
+
+/** Moves settings, logs and install pointers to `dir`; returns the folder now in use. */
+export async function setAppDataLocation(dir: string): Promise<string> {
+  return invoke<string>("set_app_data_location", { dir });
+}
+
+export async function onFirstRun(handler: () => void): Promise<() => void> {
+  return listen("first-run", () => handler());
 }
 
 export async function pickStorageFolder(): Promise<string | null> {
   return invoke<string | null>("pick_storage_folder");
 }
 
+export interface StorageCheck {
+  ok: boolean;
+  writable: boolean;
+  freeBytes: number | null;
+  isNetworkDrive: boolean;
+  issue: "not_found" | "not_writable" | "network_drive" | "low_disk_space" | null;
+}
+
+export async function validateStorageFolder(path: string): Promise<StorageCheck> {
+  return invoke<StorageCheck>("validate_storage_folder", { path });
+}
+
+export interface DriveInfo {
+  driveType: "fixed" | "removable" | "network" | "cdrom" | "ram" | "unknown";
+  removable: boolean;
+  network: boolean;
+  filesystem: string | null;
+}
+
+export async function classifyDrive(path: string): Promise<DriveInfo> {
+  return invoke<DriveInfo>("classify_drive", { path });
+}
+
+/** Writes the samples of the last recording GPU monitor run to `path` as CSV. */
+export async function exportGpuSamples(path: string): Promise<void> {
+  await invoke("export_gpu_samples", { path });
+}
+
+/** Same as the tray click; a window left on a disconnected monitor is moved back on screen. */
+export async function toggleMainWindow(): Promise<boolean> {
+  return invoke<boolean>("toggle_main_window");
+}
+
 export async function setTrayStatus(tooltip: string): Promise<void> {
   await invoke("set_tray_status", { tooltip });
 }
@@ -49,6 +524,59 @@ export async function setTrayStatus(tooltip: string): Promise<void> {
 export interface NvidiaInfo {
   gpuName: string;
   cudaVersion: string;
+  gpuUuid: string | null;
+  driverVersion: string | null;
+  /** Soft warning: the GPU was detected, but the driver reports suggest torch may not load. */
+  consistencyWarning: string | null;
+}
+
+export interface NvidiaGpu {
+  index: number;
+  name: string;
+  uuid: string;
+}
+
+export interface GpuDevice {
+  name: string;
+  vendor: "nvidia" | "amd" | "intel" | "other";
+  /** Only NVIDIA devices with a working driver can run the GPU stack. */
+  cudaCapable: boolean;
+  uuid: string | null;
+}
+
+export interface SystemInfo {
+  environment: "native" | "wsl" | "container";
+  os: string;
+  arch: string;
+  nvidia: NvidiaInfo | null;
+  elevated: boolean;
+  archCompatibility: ArchReport;
+  cpuCount: number;
+}
+
+export interface ArchReport {
+  ok: boolean;
+  appArch: string;
+  hostArch: string | null;
+  /** Running under Rosetta or Windows x64 emulation. */
+  translated: boolean;
+  message: string;
+}
+
+export async function checkArchCompatibility(): Promise<ArchReport> {
+  return invoke<ArchReport>("check_arch_compatibility");
+}
+
+export async function runtimeMode(): Promise<"dev" | "production"> {
+  return invoke<"dev" | "production">("runtime_mode");
+}
+
+export async function systemInfo(): Promise<SystemInfo> {
+  return invoke<SystemInfo>("system_info");
+}
+
+export async function isElevated(): Promise<boolean> {
+  return invoke<boolean>("is_elevated");
 }
 
 export async function getDefaultInstallDir(): Promise<string> {
@@ -59,17 +587,595 @@ export async function detectNvidia(): Promise<NvidiaInfo | null> {
   return invoke<NvidiaInfo | null>("detect_nvidia");
 }
 
+/** Queries nvidia-smi; `auto` while the model is still loading. Updates `activeDevice`. */
+export async function backendDevice(): Promise<string> {
+  return invoke<string>("backend_device");
+}
+
+export interface CudaReport {
+  available: boolean;
+  deviceCount: number;
+  /** CUDA version torch was built against; null for a CPU-only torch. */
+  torchCudaBuild: string | null;
+  /** Next step when `available` is false. */
+  remediation: string | null;
+}
+
+/** Asks the installed torch whether it can use CUDA; the definitive post-install GPU check. */
+export async function verifyCuda(): Promise<CudaReport> {
+  return invoke<CudaReport>("verify_cuda");
+}
+
+/**
+ * Whether the installed torch came from the `expectedStack` index; a CPU wheel in a GPU install
+ * returns false. `switchStack` with the same stack repairs it.
+ */
+export async function verifyTorchIndex(expectedStack: "gpu" | "cpu"): Promise<boolean> {
+  return invoke<boolean>("verify_torch_index", { expectedStack });
+}
+
+export interface ResourceFile {
+  name: string;
+  status: "ok" | "missing" | "mismatch" | "unverified";
+}
+
+export interface ResourcesReport {
+  ok: boolean;
+  checksumsBundled: boolean;
+  files: ResourceFile[];
+}
+
+/** Presence and, when checksums are bundled, integrity of the installer payload. */
+export async function verifyResources(): Promise<ResourcesReport> {
+  return invoke<ResourcesReport>("verify_resources");
+}
+
+export interface EnvVersions {
+  python: string;
+  torch: string | null;
+  /** CUDA version torch was built against; null for a CPU build. */
+  torchCuda: string | null;
+  keyvox: string | null;
+}
+
+/** Versions of the active install from a single interpreter run; cached for a few seconds. */
+export async function environmentVersions(): Promise<EnvVersions> {
+  return invoke<EnvVersions>("environment_versions");
+}
+
+export interface DirSuggestion {
+  path: string;
+  source: "app_data" | "local_data" | "home" | "drive";
+  freeBytes: number | null;
+  driveType: string;
+  /** Less free space than a GPU install needs. */
+  lowSpace: boolean;
+  /** The best candidate; never on a removable or network drive. */
+  recommended: boolean;
+}
+
+/** Candidate install folders with their free space, best first. */
+export async function suggestInstallDir(): Promise<DirSuggestion[]> {
+  return invoke<DirSuggestion[]>("suggest_install_dir");
+}
+
+export async function detectNvidiaAll(): Promise<NvidiaGpu[]> {
+  return invoke<NvidiaGpu[]>("detect_nvidia_all");
+}
+
+export async function detectGpus(): Promise<GpuDevice[]> {
+  return invoke<GpuDevice[]>("detect_gpus");
+}
+
+export interface GpuDmonSample {
+  index: number;
+  powerWatts: number | null;
+  temperatureC: number | null;
+  smPercent: number | null;
+  memPercent: number | null;
+  memUsedMib: number | null;
+}
+
+/**
+ * Streams `nvidia-smi dmon` samples until the returned function is called. With `record`, the
+ * samples are also kept for `exportGpuSamples`.
+ */
+export async function startGpuDmon(
+  handler: (sample: GpuDmonSample) => void,
+  intervalSecs?: number,
+  record?: boolean,
+): Promise<() => void> {
+  const unlisten = await listen<GpuDmonSample>("gpu-dmon", (e) => handler(e.payload));
+  try {
+    await invoke("start_gpu_dmon", { intervalSecs, record });
+  } catch (err) {
+    unlisten();
+    throw err;
+  }
+  return () => {
+    unlisten();
+    void invoke("stop_gpu_dmon");
+  };
+}
+
+export interface InstallErrorPayload {
+  issueCode: string | null;
+  message: string;
+}
+
+export class BackendInstallError extends Error {
+  readonly issueCode: string | null;
+
+  constructor(payload: InstallErrorPayload) {
+    super(payload.message);
+    this.issueCode = payload.issueCode;
+  }
+
+  toString(): string {
+    return this.message;
+  }
+}
+
+function toInstallError(err: unknown): unknown {
+  if (err && typeof err === "object" && "message" in err && "issueCode" in err) {
+    return new BackendInstallError(err as InstallErrorPayload);
+  }
+  return err;
+}
+
+/** uv step an install output line came from. */
+export type InstallStep = "venv" | "torch" | "wheel";
+
+export interface InstallProgress {
+  /** Null for status lines outside any step. */
+  step: InstallStep | null;
+  line: string;
+}
+
+export type InstallProgressHandler = (line: string, step: InstallStep | null) => void;
+
+function listenInstallProgress(onProgress: InstallProgressHandler): Promise<() => void> {
+  return listen<InstallProgress>("backend-install-progress", (e) => {
+    onProgress(e.payload.line, e.payload.step);
+  });
+}
+
+/** `beta` installs the newest keyvox from PyPI, pre-releases included, instead of the bundled wheel. */
+export type InstallChannel = "stable" | "beta";
+
+export interface InstallBackendOptions {
+  targetPython?: string;
+  verify?: boolean;
+  torchVersion?: string;
+  channel?: InstallChannel;
+  /** PEM bundle uv should trust, for proxies that intercept TLS. */
+  caBundle?: string;
+}
+
 export async function installBackend(
   stack: "gpu" | "cpu",
   installDir: string,
-  onProgress: (line: string) => void,
+  onProgress: InstallProgressHandler,
+  options: InstallBackendOptions = {},
+): Promise<void> {
+  const unlisten = await listenInstallProgress(onProgress);
+  try {
+    await invoke("install_backend", { stack, installDir, options });
+  } catch (err) {
+    throw toInstallError(err);
+  } finally {
+    unlisten();
+  }
+}
+
+export interface InstallEta {
+  etaSecs: number;
+  rateBytesPerSec: number;
+}
+
+export async function onInstallEta(handler: (eta: InstallEta) => void): Promise<() => void> {
+  return listen<InstallEta>("backend-install-eta", (e) => handler(e.payload));
+}
+
+export interface InstallVerifyFailed {
+  python: string;
+  error: string;
+}
+
+/** Fires after a completed install whose `import keyvox` check failed. */
+export async function onInstallVerifyFailed(
+  handler: (failure: InstallVerifyFailed) => void,
+): Promise<() => void> {
+  return listen<InstallVerifyFailed>("backend-install-verify-failed", (e) => handler(e.payload));
+}
+
+export type InstallPhase = "resolving" | "downloading" | "installing";
+
+export async function onInstallPhase(handler: (phase: InstallPhase) => void): Promise<() => void> {
+  return listen<InstallPhase>("backend-install-phase", (e) => handler(e.payload));
+}
+
+export async function getInstallLog(): Promise<string[]> {
+  return invoke<string[]>("get_install_log");
+}
+
+export async function repairBackend(onProgress: InstallProgressHandler): Promise<void> {
+  const unlisten = await listenInstallProgress(onProgress);
+  try {
+    await invoke("repair_backend");
+  } catch (err) {
+    throw toInstallError(err);
+  } finally {
+    unlisten();
+  }
+}
+
+export interface InstallManifest {
+  keyvoxVersion: string | null;
+  torchVersion: string | null;
+  /** Set when the install pinned `torch==<version>`. */
+  torchPin: string | null;
+  torchIndex: string;
+  pythonVersion: string | null;
+  uvVersion: string | null;
+  stack: "gpu" | "cpu";
+  /** Wheel extras in the environment; empty for manifests written before they were recorded. */
+  extras: string[];
+  /** Seconds since the Unix epoch. */
+  installedAt: number;
+  wheelFile: string;
+  wheelSha256: string;
+}
+
+export type OptionalExtra = "nvidia" | "singleton" | "server";
+
+/** Adds extras to the existing install; progress streams like `repairBackend`. */
+export async function installExtras(
+  extras: OptionalExtra[],
+  onProgress: InstallProgressHandler,
+): Promise<void> {
+  const unlisten = await listenInstallProgress(onProgress);
+  try {
+    await invoke("install_extras", { extras });
+  } catch (err) {
+    throw toInstallError(err);
+  } finally {
+    unlisten();
+  }
+}
+
+/**
+ * Reinstalls torch for `newStack` into the existing environment and restarts a running backend.
+ * Progress streams like `repairBackend`, tagged with the `torch` step.
+ */
+export async function switchStack(
+  newStack: "gpu" | "cpu",
+  onProgress: InstallProgressHandler,
+): Promise<void> {
+  const unlisten = await listenInstallProgress(onProgress);
+  try {
+    await invoke("switch_stack", { newStack });
+  } catch (err) {
+    throw toInstallError(err);
+  } finally {
+    unlisten();
+  }
+}
+
+export async function readInstallManifest(): Promise<InstallManifest | null> {
+  return invoke<InstallManifest | null>("read_install_manifest");
+}
+
+export interface DirSize {
+  label: "environment" | "torch" | "nvidia" | "keyvox" | "model_cache";
+  path: string;
+  bytes: number;
+}
+
+export async function installDiskBreakdown(): Promise<DirSize[]> {
+  return invoke<DirSize[]>("install_disk_breakdown");
+}
+
+export interface IntegrityProblem {
+  severity: "error" | "warning";
+  issue: "dangling_link" | "base_python_missing" | "python_missing" | "keyvox_missing" | "torch_missing";
+  path: string;
+}
+
+export interface IntegrityReport {
+  /** False when any problem is an error. */
+  ok: boolean;
+  venv: string;
+  problems: IntegrityProblem[];
+  /** `repair` maps to `repairBackend`; `reinstall` needs the full installer. */
+  suggestion: "repair" | "reinstall" | null;
+}
+
+export async function scanInstallIntegrity(): Promise<IntegrityReport> {
+  return invoke<IntegrityReport>("scan_install_integrity");
+}
+
+export interface UninstallEntry {
+  kind: "environment" | "manifest" | "pointer";
+  path: string;
+  bytes: number;
+}
+
+export interface UninstallPlan {
+  entries: UninstallEntry[];
+  totalBytes: number;
+}
+
+/** What `uninstallBackend` would delete; nothing is touched. */
+export async function uninstallPreview(): Promise<UninstallPlan> {
+  return invoke<UninstallPlan>("uninstall_preview");
+}
+
+/** Deletes the entries of `uninstallPreview` after stopping the backend; emits `first-run`. */
+export async function uninstallBackend(): Promise<void> {
+  await invoke("uninstall_backend");
+}
+
+export async function backendVersion(): Promise<string | null> {
+  return invoke<string | null>("backend_version");
+}
+
+export interface ConflictInfo {
+  path: string;
+  source: "install" | "targetPython" | "defaultVenv" | "path";
+  version: string | null;
+  /** What the app runs when no backend command override is set. */
+  active: boolean;
+  /** The first `keyvox` on PATH, and not the venv's: it shadows the venv outside the app. */
+  shadowsVenv: boolean;
+}
+
+export async function detectBackendConflicts(): Promise<ConflictInfo[]> {
+  return invoke<ConflictInfo[]>("detect_backend_conflicts");
+}
+
+export interface CompatReport {
+  ok: boolean;
+  status: "ok" | "too_old" | "too_new" | "unknown";
+  backendVersion: string | null;
+  supportedRange: string;
+  /** Includes the recommended action (update the app or the backend) when not ok. */
+  message: string;
+}
+
+export async function checkCompatibility(): Promise<CompatReport> {
+  return invoke<CompatReport>("check_compatibility");
+}
+
+export interface ProtocolReport {
+  compatible: boolean;
+  status: "ok" | "backend_older" | "backend_newer" | "unknown";
+  expected: string;
+  backendProtocol: string | null;
+  /** Says which side to update when not compatible. */
+  message: string;
+}
+
+export async function checkProtocol(expected: string, port?: number): Promise<ProtocolReport> {
+  return invoke<ProtocolReport>("check_protocol", { expected, port: port ?? null });
+}
+
+export interface VersionComparison {
+  bundled: string;
+  /** Null when no backend is installed yet. */
+  installed: string | null;
+  /** The bundled wheel is newer than the installed backend; `repairBackend` installs it. */
+  updateAvailable: boolean;
+}
+
+/** Offline check of the wheel shipped with this app against the installed backend. */
+export async function bundledVsInstalled(): Promise<VersionComparison> {
+  return invoke<VersionComparison>("bundled_vs_installed");
+}
+
+export interface UpdateStatus {
+  current: string;
+  /** Newer desktop version offered by the updater; null when up to date. */
+  available: string | null;
+  notes: string | null;
+  /** Set on `app-update-status` when the tray's check failed. */
+  error: string | null;
+}
+
+export interface AppUpdateProgress {
+  downloaded: number;
+  total: number | null;
+}
+
+export async function checkAppUpdate(): Promise<UpdateStatus> {
+  return invoke<UpdateStatus>("check_app_update");
+}
+
+/** Stops the managed backend, installs the update and restarts the app. */
+export async function installAppUpdate(
+  onProgress?: (progress: AppUpdateProgress) => void,
 ): Promise<void> {
-  const unlisten = await listen<string>("backend-install-progress", (e) => {
-    onProgress(e.payload);
+  const unlisten = await listen<AppUpdateProgress>("app-update-progress", (e) => {
+    onProgress?.(e.payload);
   });
   try {
-    await invoke("install_backend", { stack, installDir });
+    await invoke("install_app_update");
   } finally {
     unlisten();
   }
 }
+
+/** Result of the tray menu's "Check for Updates". */
+export async function onAppUpdateStatus(handler: (status: UpdateStatus) => void): Promise<() => void> {
+  return listen<UpdateStatus>("app-update-status", (e) => handler(e.payload));
+}
+
+export interface InstallInfo {
+  installDir: string;
+  keyvoxExe: string;
+  exeFound: boolean;
+  version: string | null;
+  source: "saved" | "default" | "scanned";
+}
+
+export async function listInstalls(): Promise<InstallInfo[]> {
+  return invoke<InstallInfo[]>("list_installs");
+}
+
+/** Cleans a BOM/CRLF-damaged install pointer; resolves to whether a file was rewritten. */
+export async function repairInstallPointer(): Promise<boolean> {
+  return invoke<boolean>("repair_install_pointer");
+}
+
+/** Prefers an existing install on the next backend start without moving any files. */
+export async function selectInstall(installDir: string): Promise<InstallInfo> {
+  return invoke<InstallInfo>("select_install", { installDir });
+}
+
+/** Resolves to null when PyPI is unreachable so callers can skip the update badge silently. */
+export async function latestKeyvoxVersion(): Promise<string | null> {
+  try {
+    return await invoke<string>("latest_keyvox_version");
+  } catch (err) {
+    if (err === "network_unavailable") {
+      return null;
+    }
+    throw err;
+  }
+}
+
+/** `standby`: a keep-alive stop left the backend running; see `BackendStatus.standby`. */
+export type BackendPhase =
+  | "starting"
+  | "ready"
+  | "stopping"
+  | "stopped"
+  | "standby"
+  | "crashed"
+  | "installing";
+
+export interface BackendStateChange {
+  phase: BackendPhase;
+  status: BackendStatus;
+}
+
+/** Re-emits the current status as a `backend-state-changed` event to every window. */
+export async function broadcastStatus(): Promise<BackendStatus> {
+  return invoke<BackendStatus>("broadcast_status");
+}
+
+export async function onBackendStateChanged(handler: (change: BackendStateChange) => void): Promise<() => void> {
+  return listen<BackendStateChange>("backend-state-changed", (e) => handler(e.payload));
+}
+
+export interface BackendCrash {
+  status: BackendStatus;
+  logPath: string | null;
+}
+
+export interface BackendHealth {
+  healthy: boolean;
+  consecutiveFailures: number;
+  reason: "start_timeout" | "probe_failed" | null;
+}
+
+export async function onBackendHealth(handler: (health: BackendHealth) => void): Promise<() => void> {
+  return listen<BackendHealth>("backend-health", (e) => handler(e.payload));
+}
+
+/** Removes lock and PID files left by a backend that is no longer running; returns their paths. */
+export async function cleanStaleLocks(): Promise<string[]> {
+  return invoke<string[]>("clean_stale_locks");
+}
+
+export async function onBackendCrashed(handler: (crash: BackendCrash) => void): Promise<() => void> {
+  return listen<BackendCrash>("backend-crashed", (e) => handler(e.payload));
+}
+
+/** Fires when the launch at app start (the `autostartBackend` setting) fails. */
+export async function onAutostartFailed(
+  handler: (error: InstallErrorPayload) => void,
+): Promise<() => void> {
+  return listen<InstallErrorPayload>("backend-autostart-failed", (e) => handler(e.payload));
+}
+
+export type NotificationPermission = "granted" | "denied" | "prompt";
+
+/** Prompts for notification permission if undecided; crash notifications need it. */
+export async function requestNotificationPermission(): Promise<NotificationPermission> {
+  return invoke<NotificationPermission>("request_notification_permission");
+}
+
+export async function notificationPermissionStatus(): Promise<NotificationPermission> {
+  return invoke<NotificationPermission>("notification_permission_status");
+}
+
+export interface CrashRecord {
+  /** Seconds since the Unix epoch. */
+  timestamp: number;
+  exitCode: number | null;
+  stderrTail: string[];
+}
+
+/** Recorded crashes, oldest first; persisted across app restarts. */
+export async function crashHistory(limit?: number): Promise<CrashRecord[]> {
+  return invoke<CrashRecord[]>("crash_history", { limit });
+}
+
+export async function openCrashLogs(): Promise<void> {
+  await invoke("open_crash_logs");
+}
+
+export async function clearBackendLog(): Promise<void> {
+  await invoke("clear_backend_log");
+}
+
+export async function onBackendLogCleared(handler: () => void): Promise<() => void> {
+  return listen("backend-log-cleared", () => handler());
+}
+
+export interface LeveledLogLine extends BackendLogLine {
+  level: "debug" | "info" | "warning" | "error" | "critical";
+}
+
+/** Last `maxLines` lines of the backend log file at or above `minLevel`. */
+export async function readBackendLogFiltered(
+  minLevel: LeveledLogLine["level"],
+  maxLines: number,
+): Promise<LeveledLogLine[]> {
+  return invoke<LeveledLogLine[]>("read_backend_log_filtered", { minLevel, maxLines });
+}
+
+export async function onBackendLog(handler: (line: BackendLogLine) => void): Promise<() => void> {
+  return listen<BackendLogLine>("backend-log", (e) => handler(e.payload));
+}
+
+/**
+ * Replays buffered backend output, then streams new lines until the returned
+ * function is called.
+ */
+export async function subscribeBackendLog(
+  handler: (line: BackendLogLine) => void,
+  limit?: number,
+): Promise<() => void> {
+  const unlisten = await listen<BackendLogLine>("backend-log", (e) => handler(e.payload));
+  const replay = await invoke<BackendLogLine[]>("subscribe_backend_log", { limit });
+  replay.forEach(handler);
+  return () => {
+    unlisten();
+    void invoke("unsubscribe_backend_log");
+  };
+}
+
+export interface GpuFallback {
+  line: string;
+}
+
+/** Fires when a GPU-stack backend ended up loading its model on CPU (often a driver issue). */
+export async function onBackendGpuFallback(handler: (fallback: GpuFallback) => void): Promise<() => void> {
+  return listen<GpuFallback>("backend-gpu-fallback", (e) => handler(e.payload));
+}
+
+export async function onBackendErrorLine(handler: (line: BackendErrorLine) => void): Promise<() => void> {
+  return listen<BackendErrorLine>("backend-error-line", (e) => handler(e.payload));
+}
@@ -1,3 +1,6 @@
+/** Protocol version this frontend was built against; compared with the backend's on connect. */
+export const PROTOCOL_VERSION = "1.0.0";
+
 export interface ProtocolError {
   code: string;
   message: string;
@@ -139,6 +142,18 @@ export interface HistoryEntry {
   status: string;
 }
 
+export interface BenchReport {
+  iterations: number;
+  succeeded: number;
+  failed: number;
+  timed_out: number;
+  min_ms: number | null;
+  max_ms: number | null;
+  mean_ms: number | null;
+  device: string | null;
+  errors: string[];
+}
+
 export interface ServerEventBase {
   protocol_version: string;
   timestamp: string;
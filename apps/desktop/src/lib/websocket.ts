@@ -1,4 +1,4 @@
-import type { IncomingMessage, ProtocolResponse } from "./protocol";
+import type { BenchReport, IncomingMessage, ProtocolResponse } from "./protocol";
 import { isProtocolResponse } from "./protocol";
 
 type ConnectionStatus = "disconnected" | "connecting" | "connected" | "error";
@@ -85,6 +85,60 @@ export class KeyvoxWsClient {
     });
   }
 
+  /**
+   * Time `iterations` sequential `benchmark_inference` requests.
+   *
+   * Latency is the backend-reported inference time, so socket overhead is
+   * excluded. Timed-out and failed iterations are counted but do not abort
+   * the run; stats cover the successful ones only.
+   */
+  async benchmarkInference(iterations: number, timeoutMs = 60000): Promise<BenchReport> {
+    if (!Number.isInteger(iterations) || iterations < 1 || iterations > 100) {
+      throw new Error("Benchmark iterations must be between 1 and 100");
+    }
+
+    const samples: number[] = [];
+    const errors: string[] = [];
+    let timedOut = 0;
+    let device: string | null = null;
+
+    for (let i = 0; i < iterations; i += 1) {
+      try {
+        const response = await this.sendCommand("benchmark_inference", {}, timeoutMs);
+        const ms = Number(response.result?.inference_ms);
+        if (!Number.isFinite(ms)) {
+          errors.push("Backend returned no inference_ms");
+          continue;
+        }
+        samples.push(ms);
+        device = (response.result?.device as string | undefined) ?? device;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        if (message.startsWith("Request timed out")) {
+          timedOut += 1;
+        } else {
+          errors.push(message);
+        }
+        if (!this.isConnected()) {
+          break;
+        }
+      }
+    }
+
+    const total = samples.reduce((sum, ms) => sum + ms, 0);
+    return {
+      iterations,
+      succeeded: samples.length,
+      failed: iterations - samples.length - timedOut,
+      timed_out: timedOut,
+      min_ms: samples.length ? Math.min(...samples) : null,
+      max_ms: samples.length ? Math.max(...samples) : null,
+      mean_ms: samples.length ? total / samples.length : null,
+      device,
+      errors,
+    };
+  }
+
   private async tryOpen(port: number): Promise<boolean> {
     return new Promise<boolean>((resolve) => {
       const socket = new WebSocket(`ws://localhost:${port}`);
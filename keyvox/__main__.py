@@ -1,7 +1,10 @@
 """Main entry point for Keyvox."""
 import argparse
+import json
+import logging
 import sys
 import warnings
+from pathlib import Path
 
 # Suppress transformers FutureWarning about TRANSFORMERS_CACHE
 warnings.filterwarnings("ignore", category=FutureWarning, module="transformers.utils.hub")
@@ -77,12 +80,87 @@ def _make_output_fn(config):
     return output_fn
 
 
-def _run_server_mode(config, port: int) -> None:
+def _load_config(config_path: str | None):
+    """Load the config named by ``--config``, or the auto-discovered one."""
+    if config_path is None:
+        return load_config()
+    return load_config(Path(config_path))
+
+
+def _apply_log_level(level: str | None) -> None:
+    """Set the level of library loggers (websockets, faster-whisper) from ``--log-level``."""
+    if level:
+        logging.basicConfig(level=level.upper())
+
+
+def _apply_cpu_fallback(config) -> None:
+    """Switch a CUDA model config to CPU when no GPU is usable at runtime."""
+    model_cfg = config.get("model", {})
+    if not str(model_cfg.get("device", "")).startswith("cuda"):
+        return
+    try:
+        import torch
+        if torch.cuda.is_available():
+            return
+    except ImportError:
+        pass
+
+    print("[WARN] CUDA is not available; falling back to CPU")
+    model_cfg["device"] = "cpu"
+    # Half-precision compute types are GPU-only.
+    if model_cfg.get("compute_type") in {"float16", "int8_float16"}:
+        model_cfg["compute_type"] = "int8"
+
+
+def _apply_workers(config, workers: int | None) -> None:
+    """Override the model's CPU thread count from ``--workers``."""
+    if workers is not None:
+        config.setdefault("model", {})["cpu_threads"] = workers
+
+
+def _apply_model(config, model: str | None) -> None:
+    """Override the configured model name from ``--model``."""
+    if model:
+        config.setdefault("model", {})["name"] = model
+
+
+def _run_prewarm(config) -> None:
+    """Download and load the configured model so the first real use does not pay for it."""
+    print("[INFO] Prewarming model...")
+    try:
+        create_transcriber(config)
+    except Exception as e:
+        print(f"[ERR] Prewarm failed: {e}")
+        sys.exit(1)
+    print("[OK] Model ready")
+
+
+def _run_migrations(config) -> None:
+    """Apply pending data migrations, then print a machine-readable summary line."""
+    from .history import migrate_history_db, resolve_history_db_path
+
+    db_path = resolve_history_db_path(config)
+    print(f"[INFO] Checking history database: {db_path}")
+
+    def on_step(from_version: int, to_version: int) -> None:
+        print(f"[INFO] Migrating history schema {from_version} -> {to_version}")
+
+    try:
+        report = migrate_history_db(db_path, on_step=on_step)
+    except Exception as e:
+        print(f"[ERR] Migration failed: {e}")
+        sys.exit(1)
+    print(f"[OK] History schema at version {report['to_version']}")
+    # The desktop app reads this line; keep it last and on one line.
+    print(f"[MIGRATE] {json.dumps(report)}")
+
+
+def _run_server_mode(config, port: int, host: str = "localhost") -> None:
     """Run Keyvox as WebSocket server."""
     try:
         from .server import KeyvoxServer
 
-        server = KeyvoxServer(config=config, port=port)
+        server = KeyvoxServer(config=config, port=port, host=host)
         server.run()
     except ModuleNotFoundError as e:
         if getattr(e, "name", "") == "websockets":
@@ -157,12 +235,54 @@ def main() -> None:
         action="store_true",
         help="Run as WebSocket server"
     )
+    mode_group.add_argument(
+        "--prewarm",
+        action="store_true",
+        help="Download and load the configured model, then exit"
+    )
+    mode_group.add_argument(
+        "--migrate",
+        action="store_true",
+        help="Apply pending data migrations, then exit"
+    )
     parser.add_argument(
         "--port",
         type=int,
         default=9876,
         help="WebSocket server port (default: 9876, used with --server)"
     )
+    parser.add_argument(
+        "--host",
+        default="localhost",
+        help="Address to bind, e.g. 127.0.0.1 or ::1 to pin one family (default: localhost)"
+    )
+    parser.add_argument(
+        "--workers",
+        type=int,
+        default=None,
+        help="CPU threads used for inference (default: the backend's own choice)"
+    )
+    parser.add_argument(
+        "--model",
+        default=None,
+        help="Model to load instead of the one in the config file"
+    )
+    parser.add_argument(
+        "--allow-cpu-fallback",
+        action="store_true",
+        help="Load the model on CPU instead of failing when CUDA is unavailable"
+    )
+    parser.add_argument(
+        "--config",
+        default=None,
+        help="Config file to load instead of the auto-discovered config.toml"
+    )
+    parser.add_argument(
+        "--log-level",
+        choices=["debug", "info", "warning", "error", "critical"],
+        default=None,
+        help="Log level for library output (default: Python's own)"
+    )
 
     args = parser.parse_args()
 
@@ -171,6 +291,27 @@ def main() -> None:
         run_wizard()
         return
 
+    if args.workers is not None and args.workers < 1:
+        parser.error("--workers must be at least 1")
+    if args.config is not None and not Path(args.config).is_file():
+        parser.error(f"--config file not found: {args.config}")
+    _apply_log_level(args.log_level)
+
+    # Prewarming only touches the model cache, so it may run next to a live instance.
+    if args.prewarm:
+        config = _load_config(args.config)
+        if args.allow_cpu_fallback:
+            _apply_cpu_fallback(config)
+        _apply_workers(config, args.workers)
+        _apply_model(config, args.model)
+        _run_prewarm(config)
+        return
+
+    # Run by the desktop app before serving, while no server holds the database.
+    if args.migrate:
+        _run_migrations(_load_config(args.config))
+        return
+
     # Check for single instance
     if not _check_single_instance():
         print("[ERR] Keyvox is already running")
@@ -178,10 +319,14 @@ def main() -> None:
         sys.exit(1)
 
     # Load configuration
-    config = load_config()
+    config = _load_config(args.config)
+    if args.allow_cpu_fallback:
+        _apply_cpu_fallback(config)
+    _apply_workers(config, args.workers)
+    _apply_model(config, args.model)
 
     if args.server:
-        _run_server_mode(config=config, port=args.port)
+        _run_server_mode(config=config, port=args.port, host=args.host)
         return
 
     _run_headless_mode(config=config)
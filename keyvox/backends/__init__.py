@@ -43,6 +43,8 @@ def create_transcriber(config: Dict[str, Any]) -> TranscriberBackend:
     device = config["model"]["device"]
     compute_type = config["model"]["compute_type"]
     model_cache = str(resolve_model_cache_root(config))
+    # 0 keeps the library's own thread count.
+    cpu_threads = int(config["model"].get("cpu_threads") or 0)
 
     # Create backend instance
     if backend == "faster-whisper":
@@ -52,7 +54,8 @@ def create_transcriber(config: Dict[str, Any]) -> TranscriberBackend:
                 model_name=model_name,
                 device=device,
                 compute_type=compute_type,
-                model_cache=model_cache
+                model_cache=model_cache,
+                cpu_threads=cpu_threads,
             )
         except ImportError as e:
             print("[ERR] Backend 'faster-whisper' requires the 'faster-whisper' package.")
@@ -17,7 +17,8 @@ class FasterWhisperBackend:
         model_name: str = "large-v3-turbo",
         device: str = "cuda",
         compute_type: str = "float16",
-        model_cache: str = ""
+        model_cache: str = "",
+        cpu_threads: int = 0,
     ):
         # Set cache paths BEFORE importing faster_whisper
         if model_cache:
@@ -28,8 +29,10 @@ class FasterWhisperBackend:
 
         self.model_name = model_name
         print(f"[INFO] Loading Faster Whisper model: {model_name} on {device}...")
+        # Only passed when set, so CTranslate2 keeps choosing its own default.
+        extra = {"cpu_threads": cpu_threads} if cpu_threads else {}
         try:
-            self.model = WhisperModel(model_name, device=device, compute_type=compute_type)
+            self.model = WhisperModel(model_name, device=device, compute_type=compute_type, **extra)
             print("[OK] Model loaded and ready")
         except Exception as e:
             msg = str(e).lower()
@@ -5,13 +5,15 @@ import csv
 import sqlite3
 from contextlib import contextmanager
 from pathlib import Path
-from typing import Any, Dict, List
+from typing import Any, Callable, Dict, List
 
 from .config import get_config_path
 from .storage import resolve_history_db_path as resolve_history_db_path_from_storage
 
 DEFAULT_DB_FILENAME = "history.sqlite3"
 MAX_HISTORY_LIMIT = 1000
+# Stored in SQLite's user_version; bump together with a new entry in _HISTORY_MIGRATIONS.
+HISTORY_SCHEMA_VERSION = 1
 
 
 def resolve_history_db_path(config: Dict[str, Any]) -> Path:
@@ -20,6 +22,66 @@ def resolve_history_db_path(config: Dict[str, Any]) -> Path:
     return resolve_history_db_path_from_storage(config, config_path=config_path)
 
 
+def _create_transcriptions(conn: sqlite3.Connection) -> None:
+    conn.execute(
+        """
+        CREATE TABLE IF NOT EXISTS transcriptions (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            text TEXT NOT NULL,
+            duration_ms INTEGER,
+            backend TEXT NOT NULL,
+            model TEXT NOT NULL,
+            status TEXT NOT NULL DEFAULT 'ok'
+        )
+        """
+    )
+    conn.execute(
+        """
+        CREATE INDEX IF NOT EXISTS idx_transcriptions_created_at
+        ON transcriptions(created_at DESC)
+        """
+    )
+
+
+# (from_version, to_version, migration_fn). Databases created before versioning are at 0 and
+# already have the table, so v0 -> v1 must stay idempotent.
+_HISTORY_MIGRATIONS: list = [
+    (0, 1, _create_transcriptions),
+]
+
+
+def migrate_history_db(
+    db_path: Path,
+    on_step: Callable[[int, int], None] | None = None,
+) -> Dict[str, int]:
+    """Apply pending schema migrations to the history DB.
+
+    Returns the schema version found, the version reached, and how many migrations ran.
+    A database from a newer Keyvox is left untouched.
+    """
+    db_path = Path(db_path)
+    db_path.parent.mkdir(parents=True, exist_ok=True)
+    conn = sqlite3.connect(str(db_path))
+    try:
+        from_version = int(conn.execute("PRAGMA user_version").fetchone()[0])
+        version = from_version
+        applied = 0
+        for from_v, to_v, fn in _HISTORY_MIGRATIONS:
+            if version != from_v:
+                continue
+            if on_step is not None:
+                on_step(from_v, to_v)
+            fn(conn)
+            conn.execute(f"PRAGMA user_version = {int(to_v)}")
+            conn.commit()
+            version = to_v
+            applied += 1
+    finally:
+        conn.close()
+    return {"from_version": from_version, "to_version": version, "applied": applied}
+
+
 class HistoryStore:
     """Persist and query transcriptions in SQLite."""
 
@@ -46,27 +108,7 @@ class HistoryStore:
             conn.close()
 
     def _init_db(self) -> None:
-        with self._connection() as conn:
-            conn.execute(
-                """
-                CREATE TABLE IF NOT EXISTS transcriptions (
-                    id INTEGER PRIMARY KEY AUTOINCREMENT,
-                    created_at TEXT NOT NULL DEFAULT (datetime('now')),
-                    text TEXT NOT NULL,
-                    duration_ms INTEGER,
-                    backend TEXT NOT NULL,
-                    model TEXT NOT NULL,
-                    status TEXT NOT NULL DEFAULT 'ok'
-                )
-                """
-            )
-            conn.execute(
-                """
-                CREATE INDEX IF NOT EXISTS idx_transcriptions_created_at
-                ON transcriptions(created_at DESC)
-                """
-            )
-            conn.commit()
+        migrate_history_db(self.db_path)
 
     @staticmethod
     def _row_to_dict(row: sqlite3.Row) -> Dict[str, Any]:
@@ -9,6 +9,7 @@ import signal
 import threading
 import time
 from datetime import datetime, timezone
+from http import HTTPStatus
 from pathlib import Path
 from typing import Any, Dict, Optional
 
@@ -94,9 +95,15 @@ MODEL_COMPUTE_TYPES = {
 class KeyvoxServer:
     """WebSocket server wrapping the Keyvox engine pipeline."""
 
-    def __init__(self, config: Dict[str, Any], port: int = DEFAULT_PORT):
+    def __init__(
+        self,
+        config: Dict[str, Any],
+        port: int = DEFAULT_PORT,
+        host: str = "localhost",
+    ):
         self.config = config
         self.port = port
+        self.host = host
         self._client = None  # Single connected client
         self._loop: Optional[asyncio.AbstractEventLoop] = None
         self._pipeline: Optional[TranscriptionPipeline] = None
@@ -949,6 +956,63 @@ class KeyvoxServer:
             result={"pong": True},
         )
 
+    async def _cmd_benchmark_inference(
+        self,
+        websocket,
+        request_id: str | int | None,
+        msg: Dict[str, Any],
+    ) -> None:
+        duration_s = msg.get("duration_s", 1.0)
+        if (
+            isinstance(duration_s, bool)
+            or not isinstance(duration_s, (int, float))
+            or not 0 < duration_s <= 30
+        ):
+            await self._send_error(
+                websocket,
+                request_id=request_id,
+                code="invalid_payload",
+                message="benchmark_inference 'duration_s' must be a number in (0, 30]",
+            )
+            return
+
+        if self._recording_started_at is not None:
+            await self._send_error(
+                websocket,
+                request_id=request_id,
+                code="busy",
+                message="Cannot benchmark while recording",
+            )
+            return
+
+        import numpy as np
+
+        # Silence keeps the run deterministic; decode cost is what we want to time.
+        audio = np.zeros(int(16000 * duration_s), dtype=np.float32)
+        started = time.perf_counter()
+        try:
+            await asyncio.to_thread(self._transcriber.transcribe, audio)
+        except Exception as e:
+            await self._send_error(
+                websocket,
+                request_id=request_id,
+                code="inference_failed",
+                message=f"Benchmark inference failed: {e}",
+            )
+            return
+        elapsed_ms = (time.perf_counter() - started) * 1000.0
+
+        await self._send_response(
+            websocket,
+            request_id=request_id,
+            response_type="benchmark_inference",
+            result={
+                "inference_ms": round(elapsed_ms, 3),
+                "duration_s": float(duration_s),
+                "device": self.config.get("model", {}).get("device"),
+            },
+        )
+
     async def _cmd_get_capabilities(self, websocket, request_id: str | int | None) -> None:
         await self._send_response(
             websocket,
@@ -1683,6 +1747,8 @@ class KeyvoxServer:
             await self._cmd_get_server_info(websocket, request_id)
         elif cmd == "ping":
             await self._cmd_ping(websocket, request_id)
+        elif cmd == "benchmark_inference":
+            await self._cmd_benchmark_inference(websocket, request_id, msg)
         elif cmd == "get_capabilities":
             await self._cmd_get_capabilities(websocket, request_id)
         elif cmd == "get_storage_status":
@@ -1768,6 +1834,28 @@ class KeyvoxServer:
 
     # --- Server lifecycle ---
 
+    def _version_info(self) -> Dict[str, Any]:
+        return {"name": "keyvox", "protocol_version": PROTOCOL_VERSION}
+
+    def _process_http_request(self, *args):
+        """Answer ``GET /version`` over plain HTTP so clients can check compatibility first.
+
+        Any other request continues the WebSocket handshake. Both the legacy
+        ``(path, headers)`` and the newer ``(connection, request)`` hook signatures of
+        ``websockets`` are accepted.
+        """
+        first, second = args
+        path = first if isinstance(first, str) else second.path
+        if path.split("?", 1)[0] != "/version":
+            return None
+        body = json.dumps(self._version_info())
+        if isinstance(first, str):
+            return HTTPStatus.OK, [("Content-Type", "application/json")], body.encode("utf-8")
+        response = first.respond(HTTPStatus.OK, body)
+        del response.headers["Content-Type"]
+        response.headers["Content-Type"] = "application/json"
+        return response
+
     async def _start_ws(self) -> int:
         """Start WebSocket server, trying ports if busy. Returns bound port."""
         import websockets
@@ -1775,7 +1863,12 @@ class KeyvoxServer:
         port = self.port
         for attempt in range(MAX_PORT_ATTEMPTS):
             try:
-                self._server = await websockets.serve(self._handler, "localhost", port)
+                self._server = await websockets.serve(
+                    self._handler,
+                    self.host,
+                    port,
+                    process_request=self._process_http_request,
+                )
                 return port
             except OSError:
                 if attempt < MAX_PORT_ATTEMPTS - 1:
@@ -1796,7 +1889,8 @@ class KeyvoxServer:
         if bound_port != self.port:
             print(f"[WARN] Port {self.port} busy, using {bound_port}")
         self.port = bound_port
-        print(f"[OK] WebSocket server listening on ws://localhost:{self.port}")
+        host = f"[{self.host}]" if ":" in self.host else self.host
+        print(f"[OK] WebSocket server listening on ws://{host}:{self.port}")
 
         output_config = self.config.get("output", {})
 
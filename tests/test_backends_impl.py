@@ -274,3 +274,16 @@ def test_qwen_vllm_backend_sets_model_cache_and_handles_no_speech(monkeypatch):
     assert os.environ["HF_HOME"] == "D:/kv-cache"
     assert os.environ["HF_HUB_CACHE"] == os.path.join("D:/kv-cache", "hub")
     assert backend.transcribe(np.array([0.1], dtype=np.float32)) == ""
+
+
+def test_faster_whisper_backend_passes_cpu_threads_only_when_set(monkeypatch):
+    calls = []
+
+    class FakeModel:
+        def __init__(self, model_name, device, compute_type, **kwargs):
+            calls.append(kwargs)
+
+    monkeypatch.setitem(__import__("sys").modules, "faster_whisper", types.SimpleNamespace(WhisperModel=FakeModel))
+    FasterWhisperBackend(device="cpu", compute_type="int8", cpu_threads=4)
+    FasterWhisperBackend(device="cpu", compute_type="int8")
+    assert calls == [{"cpu_threads": 4}, {}]
@@ -1,7 +1,14 @@
 """Tests for SQLite transcription history storage."""
 from pathlib import Path
 
-from keyvox.history import HistoryStore, MAX_HISTORY_LIMIT
+import sqlite3
+
+from keyvox.history import (
+    HISTORY_SCHEMA_VERSION,
+    HistoryStore,
+    MAX_HISTORY_LIMIT,
+    migrate_history_db,
+)
 
 
 def test_add_list_and_count_entries(tmp_path):
@@ -104,3 +111,25 @@ def test_export_txt_and_csv(tmp_path):
     assert "hello export" in txt
     assert "hello export" in csv
     assert "created_at" in csv
+
+
+def test_migrate_history_db_versions_an_unversioned_database(tmp_path):
+    db_path = tmp_path / "history.sqlite3"
+    conn = sqlite3.connect(str(db_path))
+    conn.execute(
+        "CREATE TABLE transcriptions (id INTEGER PRIMARY KEY AUTOINCREMENT, "
+        "created_at TEXT NOT NULL DEFAULT (datetime('now')), text TEXT NOT NULL, "
+        "duration_ms INTEGER, backend TEXT NOT NULL, model TEXT NOT NULL, "
+        "status TEXT NOT NULL DEFAULT 'ok')"
+    )
+    conn.execute("INSERT INTO transcriptions (text, backend, model) VALUES ('kept', 'b', 'm')")
+    conn.commit()
+    conn.close()
+
+    steps = []
+    report = migrate_history_db(db_path, on_step=lambda a, b: steps.append((a, b)))
+
+    assert report == {"from_version": 0, "to_version": HISTORY_SCHEMA_VERSION, "applied": 1}
+    assert steps == [(0, 1)]
+    assert HistoryStore(db_path).list_entries()[0]["text"] == "kept"
+    assert migrate_history_db(db_path)["applied"] == 0
@@ -217,9 +217,10 @@ def test_main_server_mode_runs_server_with_port(monkeypatch):
     calls = {}
 
     class FakeServer:
-        def __init__(self, config, port):
+        def __init__(self, config, port, host):
             calls["config"] = config
             calls["port"] = port
+            calls["host"] = host
 
         def run(self):
             calls["run"] = True
@@ -236,6 +237,7 @@ def test_main_server_mode_runs_server_with_port(monkeypatch):
 
     assert calls["config"] is cfg
     assert calls["port"] == 9999
+    assert calls["host"] == "localhost"
     assert calls["run"] is True
 
 
@@ -243,7 +245,7 @@ def test_main_server_mode_missing_websockets_exits_with_hint(monkeypatch, capsys
     cfg = _base_config()
 
     class FakeServer:
-        def __init__(self, config, port):
+        def __init__(self, config, port, host):
             pass
 
         def run(self):
@@ -357,3 +359,108 @@ def test_module_main_guard_executes_main(monkeypatch):
 
     runpy.run_module("keyvox.__main__", run_name="__main__")
     assert called["setup"] is True
+
+
+def test_apply_cpu_fallback_switches_cuda_to_cpu_without_gpu(monkeypatch, capsys):
+    fake_torch = types.SimpleNamespace(cuda=types.SimpleNamespace(is_available=lambda: False))
+    monkeypatch.setitem(sys.modules, "torch", fake_torch)
+    cfg = {"model": {"device": "cuda", "compute_type": "float16"}}
+
+    main_mod._apply_cpu_fallback(cfg)
+
+    assert cfg["model"] == {"device": "cpu", "compute_type": "int8"}
+    assert "falling back to CPU" in capsys.readouterr().out
+
+
+def test_apply_cpu_fallback_keeps_cuda_when_gpu_available(monkeypatch):
+    fake_torch = types.SimpleNamespace(cuda=types.SimpleNamespace(is_available=lambda: True))
+    monkeypatch.setitem(sys.modules, "torch", fake_torch)
+    cfg = {"model": {"device": "cuda", "compute_type": "float16"}}
+
+    main_mod._apply_cpu_fallback(cfg)
+
+    assert cfg["model"] == {"device": "cuda", "compute_type": "float16"}
+
+
+def test_main_prewarm_loads_model_without_single_instance_check(monkeypatch, capsys):
+    cfg = _base_config()
+    calls = {}
+
+    def fail_single_instance():
+        raise AssertionError("prewarm must not take the single-instance lock")
+
+    monkeypatch.setattr(main_mod, "_check_single_instance", fail_single_instance)
+    monkeypatch.setattr(main_mod, "load_config", lambda: cfg)
+    monkeypatch.setattr(main_mod, "create_transcriber", lambda config: calls.setdefault("config", config))
+    monkeypatch.setattr(main_mod.sys, "argv", ["keyvox", "--prewarm"])
+
+    main_mod.main()
+
+    assert calls["config"] is cfg
+    assert "[OK] Model ready" in capsys.readouterr().out
+
+
+def test_main_prewarm_loads_the_model_named_on_the_command_line(monkeypatch):
+    cfg = _base_config()
+    calls = {}
+
+    monkeypatch.setattr(main_mod, "load_config", lambda: cfg)
+    monkeypatch.setattr(main_mod, "create_transcriber", lambda config: calls.setdefault("config", config))
+    monkeypatch.setattr(main_mod.sys, "argv", ["keyvox", "--prewarm", "--model", "small"])
+
+    main_mod.main()
+
+    assert calls["config"]["model"]["name"] == "small"
+
+
+def test_main_loads_the_config_named_on_the_command_line(monkeypatch, tmp_path):
+    config_file = tmp_path / "custom.toml"
+    config_file.write_text("")
+    calls = {}
+
+    def fake_load_config(path=None):
+        calls["path"] = path
+        return _base_config()
+
+    monkeypatch.setattr(main_mod, "load_config", fake_load_config)
+    monkeypatch.setattr(main_mod, "create_transcriber", lambda config: None)
+    monkeypatch.setattr(main_mod.sys, "argv", [
+        "keyvox", "--prewarm", "--config", str(config_file), "--log-level", "warning",
+    ])
+
+    main_mod.main()
+
+    assert calls["path"] == config_file
+
+
+def test_main_rejects_a_missing_config_file(monkeypatch, tmp_path):
+    monkeypatch.setattr(main_mod.sys, "argv", ["keyvox", "--config", str(tmp_path / "none.toml")])
+    with pytest.raises(SystemExit) as exc:
+        main_mod.main()
+    assert exc.value.code == 2
+
+
+def test_run_prewarm_exits_1_when_model_load_fails(monkeypatch):
+    def boom(config):
+        raise RuntimeError("download failed")
+
+    monkeypatch.setattr(main_mod, "create_transcriber", boom)
+    with pytest.raises(SystemExit) as exc:
+        main_mod._run_prewarm(_base_config())
+    assert exc.value.code == 1
+
+
+def test_main_migrate_prints_machine_readable_summary(monkeypatch, capsys, tmp_path):
+    import json
+
+    import keyvox.history as history_mod
+
+    monkeypatch.setattr(main_mod, "load_config", _base_config)
+    monkeypatch.setattr(history_mod, "resolve_history_db_path", lambda config: tmp_path / "h.sqlite3")
+    monkeypatch.setattr(main_mod.sys, "argv", ["keyvox", "--migrate"])
+
+    main_mod.main()
+
+    last = capsys.readouterr().out.strip().splitlines()[-1]
+    assert last.startswith("[MIGRATE] ")
+    assert json.loads(last[len("[MIGRATE] "):])["to_version"] == history_mod.HISTORY_SCHEMA_VERSION
@@ -776,6 +776,50 @@ def test_ping_and_server_info(monkeypatch):
     assert ws.sent[-1]["result"]["protocol_version"] == PROTOCOL_VERSION
 
 
+def test_benchmark_inference_times_transcriber(monkeypatch):
+    server, _, _ = _make_server(monkeypatch)
+    ws = _FakeWebSocket()
+    seen = []
+
+    class _Transcriber:
+        def transcribe(self, audio):
+            seen.append(len(audio))
+            return ""
+
+    server._transcriber = _Transcriber()
+
+    asyncio.run(
+        server._handle_command(
+            {"type": "benchmark_inference", "request_id": "b1", "duration_s": 0.5}, ws
+        )
+    )
+    _assert_ok_response(ws.sent[-1], "benchmark_inference", request_id="b1")
+    assert ws.sent[-1]["result"]["inference_ms"] >= 0
+    assert seen == [8000]
+
+    asyncio.run(
+        server._handle_command(
+            {"type": "benchmark_inference", "request_id": "b2", "duration_s": 0}, ws
+        )
+    )
+    _assert_error_response(ws.sent[-1], "invalid_payload", request_id="b2")
+
+
+def test_benchmark_inference_reports_transcriber_failure(monkeypatch):
+    server, _, _ = _make_server(monkeypatch)
+    ws = _FakeWebSocket()
+
+    class _Transcriber:
+        def transcribe(self, audio):
+            raise RuntimeError("CUDA out of memory")
+
+    server._transcriber = _Transcriber()
+
+    asyncio.run(server._handle_command({"type": "benchmark_inference", "request_id": "b3"}, ws))
+    _assert_error_response(ws.sent[-1], "inference_failed", request_id="b3")
+    assert "CUDA out of memory" in ws.sent[-1]["error"]["message"]
+
+
 def test_shutdown_command_requests_server_stop(monkeypatch):
     server, _, _ = _make_server(monkeypatch)
     ws = _FakeWebSocket()
@@ -820,8 +864,9 @@ def test_start_ws_falls_back_to_next_port(monkeypatch):
         async def wait_closed(self):
             return None
 
-    async def _serve(handler, host, port):
+    async def _serve(handler, host, port, process_request=None):
         calls.append((host, port))
+        assert process_request == server._process_http_request
         if port == 7000:
             raise OSError("busy")
         return _FakeWsServer()
@@ -835,6 +880,55 @@ def test_start_ws_falls_back_to_next_port(monkeypatch):
     assert server._server is not None
 
 
+def test_start_ws_binds_the_requested_host(monkeypatch):
+    server, _, _ = _make_server(monkeypatch, config=_base_config())
+    server.host = "::1"
+    calls = []
+
+    async def _serve(handler, host, port, process_request=None):
+        calls.append(host)
+        return object()
+
+    monkeypatch.setitem(sys.modules, "websockets", types.SimpleNamespace(serve=_serve))
+
+    asyncio.run(server._start_ws())
+
+    assert calls == ["::1"]
+
+
+def test_version_endpoint_answers_plain_http(monkeypatch):
+    server, _, _ = _make_server(monkeypatch, config=_base_config())
+
+    status, headers, body = server._process_http_request("/version", {})
+
+    assert status == 200
+    assert ("Content-Type", "application/json") in headers
+    assert json.loads(body) == {"name": "keyvox", "protocol_version": PROTOCOL_VERSION}
+    assert server._process_http_request("/", {}) is None
+
+
+def test_version_endpoint_supports_the_connection_hook(monkeypatch):
+    server, _, _ = _make_server(monkeypatch, config=_base_config())
+
+    class _Response:
+        def __init__(self, status, body):
+            self.status = status
+            self.body = body
+            self.headers = {"Content-Type": "text/plain; charset=utf-8"}
+
+    class _Connection:
+        def respond(self, status, text):
+            return _Response(status, text)
+
+    request = types.SimpleNamespace(path="/version?probe=1")
+    response = server._process_http_request(_Connection(), request)
+
+    assert response.status == 200
+    assert response.headers["Content-Type"] == "application/json"
+    assert json.loads(response.body)["protocol_version"] == PROTOCOL_VERSION
+    assert server._process_http_request(_Connection(), types.SimpleNamespace(path="/")) is None
+
+
 # ---------------------------------------------------------------------------
 # Input validation error paths
 # ---------------------------------------------------------------------------